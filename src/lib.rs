@@ -0,0 +1,11 @@
+mod model;
+mod oid;
+mod parser;
+mod repository;
+mod validate;
+
+pub use model::*;
+pub use oid::OidResolution;
+pub use parser::{parse_mib, parse_mib_resilient, Rule};
+pub use repository::{MibRepository, RepositoryError};
+pub use validate::{validate_module, ValidationError, ValidationErrorKind};
@@ -0,0 +1,269 @@
+//! The structured representation that the parser builds from a MIB's parse tree.
+//!
+//! `parser.rs` walks the Pest tree and produces these types; everything in
+//! this module is plain data with no dependency on Pest itself, so callers
+//! can consume a parsed MIB without linking against the grammar.
+//!
+//! `MibInfo`, `Module`, `Assignment` and everything they're built from also
+//! derive `Serialize`/`Deserialize`, so a parsed MIB can be handed to a
+//! downstream tool as JSON (or YAML, with the `yaml` feature) without that
+//! tool linking against this crate's Pest grammar at all. See
+//! `MibInfo::to_json`/`from_json`.
+
+use serde::{Deserialize, Serialize};
+
+/// Options controlling how a MIB source is parsed.
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// Print the raw Pest parse tree to stdout as it's walked, for debugging.
+    pub pretty_print: bool,
+    /// When set, `parse_mib_resilient` skips forward past a malformed
+    /// assignment instead of aborting on the first one, recording a
+    /// `Diagnostic` for each one it has to skip. Ignored by `parse_mib`,
+    /// which always fails fast on the first error.
+    pub recover: bool,
+}
+
+/// One assignment that couldn't be parsed during a resilient parse, along
+/// with where it was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// The grammar rule that was being matched when parsing failed.
+    pub rule: String,
+    /// Byte offsets into the original source, `[start, end)`.
+    pub start: usize,
+    pub end: usize,
+    /// 1-based line and column of `start`.
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// The outcome of a resilient parse: whatever modules and assignments could
+/// be recovered, plus a diagnostic for every one that couldn't.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseResult {
+    pub mib: MibInfo,
+    pub errors: Vec<Diagnostic>,
+}
+
+/// The result of parsing one or more MIB modules from a single source text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MibInfo {
+    pub modules: Vec<Module>,
+}
+
+impl MibInfo {
+    /// Serialize to a stable, documented JSON interchange format, so a
+    /// downstream tool can consume a parsed MIB without linking against
+    /// this crate's Pest grammar.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<MibInfo> {
+        serde_json::from_str(json)
+    }
+
+    /// Like `to_json`, but with every OBJECT IDENTIFIER assignment's
+    /// resolved numeric OID (and any that couldn't be resolved) attached
+    /// alongside the structural data (see `resolve_oids`).
+    pub fn to_json_with_oids(&self) -> serde_json::Result<String> {
+        let resolution = self.resolve_oids();
+        serde_json::to_string_pretty(&MibDocument {
+            mib: self,
+            oids: resolution.oids,
+            unresolved_oids: resolution.unresolved,
+        })
+    }
+
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml: &str) -> Result<MibInfo, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+}
+
+#[derive(Serialize)]
+struct MibDocument<'a> {
+    #[serde(flatten)]
+    mib: &'a MibInfo,
+    oids: std::collections::HashMap<String, Vec<u64>>,
+    unresolved_oids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Module {
+    pub name: String,
+    pub imports: Vec<Import>,
+    pub exports: Vec<String>,
+    pub assignments: Vec<Assignment>,
+}
+
+/// One `<symbols> FROM <module>` clause of a module's IMPORTS list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Import {
+    pub symbols: Vec<String>,
+    pub from_module: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Assignment {
+    pub name: String,
+    pub a_type: SmiType,
+    pub value: Option<String>,
+    pub location: Location,
+}
+
+/// Where an `Assignment` was found in its source text, so a later
+/// validation pass can report findings without re-parsing.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Location {
+    /// Byte offsets into the original source, `[start, end)`.
+    pub start: usize,
+    pub end: usize,
+    /// 1-based line and column of `start`.
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A `SIZE (...)` or plain range constraint attached to a scalar SMI type,
+/// e.g. `(0..63)` or `SIZE (0..63)`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Constraint {
+    pub min: i64,
+    pub max: i64,
+}
+
+/// `MAX-ACCESS` / `ACCESS` clause of an OBJECT-TYPE macro.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Access {
+    ReadOnly,
+    ReadWrite,
+    ReadCreate,
+    AccessibleForNotify,
+    NotAccessible,
+}
+
+/// `STATUS` clause shared by OBJECT-TYPE and MODULE-IDENTITY macros.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Status {
+    Current,
+    Deprecated,
+    Obsolete,
+    Mandatory,
+}
+
+/// The parts of an OBJECT-TYPE (or MODULE-IDENTITY) macro that describe a
+/// managed object, keyed off the `snmp_update_part` / `compliance_group`
+/// style clauses the grammar already matches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectType {
+    pub syntax: Box<SmiType>,
+    pub access: Option<Access>,
+    pub status: Option<Status>,
+    pub description: Option<String>,
+    pub index: Vec<String>,
+    /// The `DEFVAL { ... }` clause's literal value, if any, e.g. `9` in
+    /// `DEFVAL { 9 }`.
+    pub defval: Option<String>,
+}
+
+/// The semantic type of a MIB `Assignment`, as declared in its SMI syntax.
+///
+/// This replaces the earlier debug-string stub: every variant now carries
+/// the information the grammar actually parsed, rather than just the name
+/// of the Pest rule that matched.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SmiType {
+    Integer32(Option<Constraint>),
+    Unsigned32(Option<Constraint>),
+    Counter32(Option<Constraint>),
+    Gauge32(Option<Constraint>),
+    TimeTicks(Option<Constraint>),
+    OctetString(Option<Constraint>),
+    ObjectIdentifier,
+    Bits(Option<Constraint>),
+    /// A `SEQUENCE { field Type, ... }` definition, in declaration order.
+    Sequence { fields: Vec<(String, SmiType)> },
+    /// `SEQUENCE OF <type>`.
+    SequenceOf(Box<SmiType>),
+    /// A reference to another named type (built-in or user-defined), used
+    /// when the assignment's syntax is just an identifier such as
+    /// `DisplayString` or a locally defined type.
+    Named(String),
+    /// The SYNTAX/ACCESS/STATUS/DESCRIPTION/INDEX parts of an OBJECT-TYPE
+    /// (or MODULE-IDENTITY) macro.
+    ObjectType(Box<ObjectType>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_mib() -> MibInfo {
+        MibInfo {
+            modules: vec![Module {
+                name: "SYNOLOGY-SMI".to_string(),
+                imports: vec![Import {
+                    symbols: vec!["enterprises".to_string(), "Integer32".to_string()],
+                    from_module: "SNMPv2-SMI".to_string(),
+                }],
+                exports: vec![],
+                assignments: vec![
+                    Assignment {
+                        name: "synology".to_string(),
+                        a_type: SmiType::ObjectIdentifier,
+                        value: Some("{ enterprises 6574 }".to_string()),
+                        location: Location { start: 0, end: 10, line: 1, column: 1 },
+                    },
+                    Assignment {
+                        name: "synoDiskCount".to_string(),
+                        a_type: SmiType::Integer32(Some(Constraint { min: 0, max: 63 })),
+                        value: None,
+                        location: Location { start: 11, end: 20, line: 2, column: 1 },
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mib = sample_mib();
+        let json = mib.to_json().unwrap();
+        let decoded = MibInfo::from_json(&json).unwrap();
+        assert_eq!(mib, decoded);
+    }
+
+    #[test]
+    fn json_with_oids_includes_resolved_oid() {
+        let mib = sample_mib();
+        let json = mib.to_json_with_oids().unwrap();
+        let decoded: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded["oids"]["synology"], serde_json::json!([1, 3, 6, 1, 4, 1, 6574]));
+        assert!(decoded["unresolved_oids"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn json_with_oids_reports_an_unresolved_reference() {
+        let mut mib = sample_mib();
+        mib.modules[0].assignments.push(Assignment {
+            name: "dangling".to_string(),
+            a_type: SmiType::ObjectIdentifier,
+            value: Some("{ neverDefined 1 }".to_string()),
+            location: Location::default(),
+        });
+
+        let json = mib.to_json_with_oids().unwrap();
+        let decoded: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert!(decoded["oids"].get("dangling").is_none());
+        assert_eq!(decoded["unresolved_oids"], serde_json::json!(["dangling"]));
+    }
+}
@@ -0,0 +1,243 @@
+//! Resolves `OBJECT IDENTIFIER` assignments (e.g. `{ enterprises 6574 }`)
+//! into fully numeric OIDs.
+//!
+//! Each assignment's value only names its immediate parent plus the
+//! trailing sub-identifiers it adds; turning that into `1.3.6.1.4.1.6574`
+//! means walking the chain of parents back to a well-known root. Since a
+//! MIB can reference a name before it's defined later in the same file (or
+//! in an entirely different module, once imports are in play), resolution
+//! is done by repeatedly resolving whatever can be resolved until nothing
+//! more changes, rather than a single top-to-bottom pass.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{MibInfo, SmiType};
+
+/// One parsed `{ parent sub1 sub2 ... }` OBJECT IDENTIFIER value.
+#[derive(Debug, Clone, PartialEq)]
+struct OidNode {
+    parent: String,
+    sub_ids: Vec<u64>,
+}
+
+/// The result of resolving every OBJECT IDENTIFIER assignment in a `MibInfo`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OidResolution {
+    /// Every name (well-known root or defined assignment) that resolved to
+    /// a full numeric OID.
+    pub oids: HashMap<String, Vec<u64>>,
+    /// Defined names whose OID could not be resolved, either because a
+    /// parent is never defined or because the chain is cyclic.
+    pub unresolved: Vec<String>,
+}
+
+/// The standard roots every MIB implicitly has in scope.
+fn well_known_roots() -> HashMap<String, Vec<u64>> {
+    HashMap::from([
+        ("iso".to_string(), vec![1]),
+        ("org".to_string(), vec![1, 3]),
+        ("dod".to_string(), vec![1, 3, 6]),
+        ("internet".to_string(), vec![1, 3, 6, 1]),
+        ("directory".to_string(), vec![1, 3, 6, 1, 1]),
+        ("mgmt".to_string(), vec![1, 3, 6, 1, 2]),
+        ("experimental".to_string(), vec![1, 3, 6, 1, 3]),
+        ("private".to_string(), vec![1, 3, 6, 1, 4]),
+        ("enterprises".to_string(), vec![1, 3, 6, 1, 4, 1]),
+        ("security".to_string(), vec![1, 3, 6, 1, 5]),
+        ("snmpV2".to_string(), vec![1, 3, 6, 1, 6]),
+    ])
+}
+
+/// Parse the raw `{ parent sub1 sub2 ... }` text captured for an OBJECT
+/// IDENTIFIER assignment's value, e.g. `{ enterprises 6574 }` or
+/// `{ iso org(3) dod(6) }`.
+fn parse_oid_value(value: &str) -> Option<OidNode> {
+    let inner = value.trim().strip_prefix('{')?.strip_suffix('}')?;
+    let mut tokens = inner.split_whitespace();
+    let parent = tokens.next()?.to_string();
+    let sub_ids = tokens
+        .map(|t| match t.splitn(2, '(').nth(1) {
+            Some(named) => named.trim_end_matches(')'),
+            None => t,
+        })
+        .filter_map(|t| t.parse::<u64>().ok())
+        .collect();
+    Some(OidNode { parent, sub_ids })
+}
+
+impl MibInfo {
+    /// The fully resolved numeric OID for a defined name, if it
+    /// (transitively) traces back to a known root.
+    pub fn oid_for(&self, name: &str) -> Option<Vec<u64>> {
+        self.resolve_oids().oids.get(name).cloned()
+    }
+
+    /// Resolve every assignment with an OID-shaped `{ parent sub... }` value
+    /// across all modules into a numeric OID, by fixpoint iteration over
+    /// the parent/sub-identifier symbol table, so forward references
+    /// resolve regardless of definition order. Returns the resolved names
+    /// alongside any that could not be resolved (missing parent or a
+    /// cycle).
+    ///
+    /// This isn't limited to `SmiType::ObjectIdentifier`: MODULE-IDENTITY
+    /// and OBJECT-TYPE assignments are declared with exactly the same
+    /// `::= { parent sub }` value syntax and are how virtually every OID in
+    /// a real MIB is actually assigned, so any assignment whose value
+    /// parses as one is treated as an OID node regardless of its `a_type`.
+    pub fn resolve_oids(&self) -> OidResolution {
+        let mut nodes: HashMap<String, OidNode> = HashMap::new();
+        for module in &self.modules {
+            for assignment in &module.assignments {
+                if let Some(node) = assignment.value.as_deref().and_then(parse_oid_value) {
+                    nodes.insert(assignment.name.clone(), node);
+                }
+            }
+        }
+
+        let mut resolved = well_known_roots();
+        let mut unresolved: Vec<String> = nodes.keys().cloned().collect();
+
+        loop {
+            let mut progressed = false;
+            unresolved.retain(|name| {
+                let node = &nodes[name];
+                match resolved.get(&node.parent) {
+                    Some(parent_oid) => {
+                        let mut oid = parent_oid.clone();
+                        oid.extend(&node.sub_ids);
+                        resolved.insert(name.clone(), oid);
+                        progressed = true;
+                        false
+                    }
+                    None => true,
+                }
+            });
+            if !progressed {
+                break;
+            }
+        }
+
+        OidResolution { oids: resolved, unresolved }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Access, Assignment, Location, Module, ObjectType, Status};
+
+    fn oid_assignment(name: &str, value: &str) -> Assignment {
+        typed_oid_assignment(name, SmiType::ObjectIdentifier, value)
+    }
+
+    fn typed_oid_assignment(name: &str, a_type: SmiType, value: &str) -> Assignment {
+        Assignment { name: name.to_string(), a_type, value: Some(value.to_string()), location: Location::default() }
+    }
+
+    fn module(name: &str, assignments: Vec<Assignment>) -> Module {
+        Module { name: name.to_string(), imports: vec![], exports: vec![], assignments }
+    }
+
+    #[test]
+    fn resolves_against_well_known_roots() {
+        let mib = MibInfo {
+            modules: vec![module(
+                "SYNOLOGY-SMI",
+                vec![
+                    oid_assignment("synology", "{ enterprises 6574 }"),
+                    oid_assignment("synoDisk", "{ synology 2 }"),
+                ],
+            )],
+        };
+
+        assert_eq!(mib.oid_for("synology"), Some(vec![1, 3, 6, 1, 4, 1, 6574]));
+        assert_eq!(mib.oid_for("synoDisk"), Some(vec![1, 3, 6, 1, 4, 1, 6574, 2]));
+        assert!(mib.resolve_oids().unresolved.is_empty());
+    }
+
+    #[test]
+    fn resolves_the_name_number_value_notation_form() {
+        // `{ iso org(3) dod(6) internet(1) mgmt(2) 1 }` is the ASN.1 value
+        // notation most real MIBs actually use, rather than bare numbers.
+        let mib = MibInfo {
+            modules: vec![module(
+                "MIB-II",
+                vec![oid_assignment("mib-2", "{ iso org(3) dod(6) internet(1) mgmt(2) 1 }")],
+            )],
+        };
+
+        assert_eq!(mib.oid_for("mib-2"), Some(vec![1, 3, 6, 1, 2, 1]));
+    }
+
+    #[test]
+    fn forward_references_resolve_regardless_of_order() {
+        let mib = MibInfo {
+            modules: vec![module(
+                "SYNOLOGY-SMI",
+                vec![
+                    oid_assignment("synoDisk", "{ synology 2 }"),
+                    oid_assignment("synology", "{ enterprises 6574 }"),
+                ],
+            )],
+        };
+
+        assert_eq!(mib.oid_for("synoDisk"), Some(vec![1, 3, 6, 1, 4, 1, 6574, 2]));
+    }
+
+    #[test]
+    fn resolves_module_identity_and_object_type_assignments() {
+        // The vast majority of real OIDs are declared this way, not as
+        // bare `OBJECT IDENTIFIER` assignments.
+        let module_identity = ObjectType {
+            syntax: Box::new(SmiType::Named("MODULE-IDENTITY".to_string())),
+            access: None,
+            status: None,
+            description: None,
+            index: vec![],
+            defval: None,
+        };
+        let object_type = ObjectType {
+            syntax: Box::new(SmiType::Integer32(None)),
+            access: Some(Access::ReadOnly),
+            status: Some(Status::Current),
+            description: None,
+            index: vec![],
+            defval: None,
+        };
+
+        let mib = MibInfo {
+            modules: vec![module(
+                "SYNOLOGY-SMI",
+                vec![
+                    oid_assignment("synology", "{ enterprises 6574 }"),
+                    typed_oid_assignment(
+                        "synoDisk",
+                        SmiType::ObjectType(Box::new(module_identity)),
+                        "{ synology 2 }",
+                    ),
+                    typed_oid_assignment(
+                        "synoDiskCount",
+                        SmiType::ObjectType(Box::new(object_type)),
+                        "{ synoDisk 1 }",
+                    ),
+                ],
+            )],
+        };
+
+        assert_eq!(mib.oid_for("synoDisk"), Some(vec![1, 3, 6, 1, 4, 1, 6574, 2]));
+        assert_eq!(mib.oid_for("synoDiskCount"), Some(vec![1, 3, 6, 1, 4, 1, 6574, 2, 1]));
+    }
+
+    #[test]
+    fn reports_names_that_never_resolve() {
+        let mib = MibInfo {
+            modules: vec![module("BROKEN-MIB", vec![oid_assignment("dangling", "{ neverDefined 1 }")])],
+        };
+
+        let resolution = mib.resolve_oids();
+        assert_eq!(resolution.oids.get("dangling"), None);
+        assert_eq!(resolution.unresolved, vec!["dangling".to_string()]);
+    }
+}
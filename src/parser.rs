@@ -24,6 +24,170 @@ pub fn parse_mib(mib_text: &str, options: &ParseOptions) -> Result<MibInfo> {
     MibParser::mib(main_node)
 }
 
+/// Like `parse_mib`, but when `options.recover` is set, a malformed
+/// assignment doesn't abort the whole parse: it's recorded as a
+/// `Diagnostic` and parsing resumes at the next top-level assignment, so a
+/// caller fixing a large vendor MIB can see every problem in one pass
+/// instead of one at a time. With `options.recover` unset this is just
+/// `parse_mib` wrapped in a `ParseResult`.
+pub fn parse_mib_resilient(mib_text: &str, options: &ParseOptions) -> ParseResult {
+    match parse_mib(mib_text, options) {
+        Ok(mib) => ParseResult { mib, errors: vec![] },
+        Err(e) if !options.recover => {
+            ParseResult { mib: MibInfo { modules: vec![] }, errors: vec![diagnostic_from_pest_error(mib_text, &e)] }
+        }
+        Err(_) => recover_assignments(mib_text),
+    }
+}
+
+/// Re-scan `mib_text` assignment-by-assignment after a full parse failed.
+/// Pest itself has no notion of "skip the broken bit and keep going", so
+/// this works at the text level: rather than splitting on blank lines
+/// (which says nothing about where one assignment ends and the next
+/// begins — a DESCRIPTION can contain a blank paragraph break, and
+/// back-to-back assignments often have no blank line between them at all),
+/// it finds the `::= <value>` token stream actually marks every
+/// assignment's close. Each assignment is re-anchored at its own
+/// `identifier <TYPE>` header, scanned forward to its own closing `::=`,
+/// and parsed independently; a `Diagnostic` is recorded for any stray text
+/// left over between one assignment's close and the next one's header,
+/// instead of giving up on the whole file.
+fn recover_assignments(mib_text: &str) -> ParseResult {
+    let name = Regex::new(r"(?m)^\s*([A-Za-z][A-Za-z0-9-]*)\s+DEFINITIONS\b")
+        .unwrap()
+        .captures(mib_text)
+        .map(|c| c[1].to_string())
+        .unwrap_or_else(|| "UNKNOWN".to_string());
+
+    let body_start = mib_text.find("BEGIN").map(|i| i + "BEGIN".len()).unwrap_or(0);
+    let body_end = mib_text.rfind("END").unwrap_or(mib_text.len()).max(body_start);
+    let mut cursor = body_start;
+
+    // IMPORTS/EXPORTS are not recovered (the recovered module's `imports`
+    // and `exports` are left empty); skip past a leading one so it isn't
+    // mistaken for a malformed assignment.
+    loop {
+        let rest = &mib_text[cursor..body_end];
+        let trimmed = rest.trim_start();
+        let skipped = rest.len() - trimmed.len();
+        if trimmed.starts_with("IMPORTS") || trimmed.starts_with("EXPORTS") {
+            if let Some(semi) = trimmed.find(';') {
+                cursor += skipped + semi + 1;
+                continue;
+            }
+        }
+        cursor += skipped;
+        break;
+    }
+
+    // An assignment's header: an identifier followed by one of the SMI
+    // macros/builtins it can be declared with, or (for a plain type
+    // assignment like `DiskStatus ::= INTEGER { ... }`) directly by `::=`.
+    let header = Regex::new(
+        r"(?m)^[ \t]*[A-Za-z][A-Za-z0-9_-]*[ \t]+(?:OBJECT IDENTIFIER|OBJECT-TYPE|OBJECT-IDENTITY|MODULE-IDENTITY|NOTIFICATION-TYPE|TEXTUAL-CONVENTION|MODULE-COMPLIANCE|OBJECT-GROUP|NOTIFICATION-GROUP|AGENT-CAPABILITIES|OCTET STRING|INTEGER|Integer32|Unsigned32|Counter32|Gauge32|TimeTicks|BITS|SEQUENCE|::=)",
+    )
+    .unwrap();
+    // An assignment's close: its `::=` clause, with either a `{ ... }` OID
+    // value or a bare identifier value. A type assignment opens with `::=`
+    // instead, so it isn't closed this way (see the loop below).
+    let assign_op = Regex::new(r"::=\s*(?:\{[^}]*\}|[A-Za-z][A-Za-z0-9_-]*)?").unwrap();
+
+    let mut anchors: Vec<usize> = header.find_iter(&mib_text[cursor..body_end]).map(|m| cursor + m.start()).collect();
+    anchors.push(body_end);
+
+    let mut assignments = Vec::new();
+    let mut errors = Vec::new();
+
+    if anchors.first() != Some(&cursor) {
+        let leading_end = anchors.first().copied().unwrap_or(body_end);
+        let trimmed = mib_text[cursor..leading_end].trim();
+        if !trimmed.is_empty() {
+            let offset = mib_text[cursor..leading_end].find(trimmed).unwrap_or(0);
+            let start = cursor + offset;
+            errors.push(diagnostic(mib_text, "assignment", start, start + trimmed.len(), "does not match Rule::assignment"));
+        }
+    }
+
+    let mut report = |start: usize, end: usize, message: &str| {
+        let trimmed = mib_text[start..end].trim();
+        if trimmed.is_empty() {
+            return;
+        }
+        let offset = mib_text[start..end].find(trimmed).unwrap_or(0);
+        let real_start = start + offset;
+        errors.push(diagnostic(mib_text, "assignment", real_start, real_start + trimmed.len(), message));
+    };
+
+    for window in anchors.windows(2) {
+        let (window_start, window_end) = (window[0], window[1]);
+
+        // A type assignment (`Name ::= TYPE ...`) has no value after its
+        // type, so it runs all the way to the next header.
+        let window_text = &mib_text[window_start..window_end];
+        let is_type_assignment = window_text
+            .trim_start()
+            .split_once(char::is_whitespace)
+            .map_or(false, |(_, rest)| rest.trim_start().starts_with("::="));
+        let close = if is_type_assignment {
+            None
+        } else {
+            assign_op.find(window_text).map(|m| window_start + m.end())
+        };
+        let assignment_end = close.unwrap_or(window_end);
+
+        let slice = mib_text[window_start..assignment_end].trim();
+        if !slice.is_empty() {
+            match MibParser::parse(Rule::assignment, slice).ok().and_then(|nodes| nodes.single().ok()) {
+                Some(node) => match MibParser::assignment(node) {
+                    Ok(a) => assignments.push(a),
+                    Err(e) => report(window_start, assignment_end, &e.to_string()),
+                },
+                None => report(window_start, assignment_end, "does not match Rule::assignment"),
+            }
+        }
+
+        if close.is_some() {
+            // Anything between this assignment's close and the next
+            // header is stray text that doesn't belong to any assignment.
+            report(assignment_end, window_end, "does not match Rule::assignment");
+        }
+    }
+
+    let mib = MibInfo { modules: vec![Module { name, imports: vec![], exports: vec![], assignments }] };
+    ParseResult { mib, errors }
+}
+
+fn diagnostic(text: &str, rule: &str, start: usize, end: usize, message: &str) -> Diagnostic {
+    let (line, column) = line_col(text, start);
+    Diagnostic { rule: rule.to_string(), start, end, line, column, message: message.to_string() }
+}
+
+fn diagnostic_from_pest_error(text: &str, error: &Error<Rule>) -> Diagnostic {
+    diagnostic(text, "mib", 0, text.len(), &error.to_string())
+}
+
+/// Capture a `Node`'s span as a `Location`, before its children (and the
+/// span that comes with them) are consumed by `match_nodes!`.
+fn location_of(node: &Node) -> Location {
+    let span = node.as_span();
+    let (line, column) = span.start_pos().line_col();
+    Location { start: span.start(), end: span.end(), line, column }
+}
+
+fn line_col(text: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    for ch in text[..offset.min(text.len())].chars() {
+        if ch == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
 // This is the other half of the parser, using pest_consume
 // It traverses the Node tree generated by Pest (Nodes are a wrapper around Pest Pairs)
 // and generates custom structures (MibInfo and friends) that represents the content of the MIB
@@ -41,25 +205,49 @@ impl MibParser {
 
     fn module_definition(node: Node) -> Result<Module> {
         Ok(match_nodes!(node.into_children();
-            [module_identifier(mi), module_body(mbs)] => Module{ name: mi, assignments: mbs},
+            [module_identifier(mi), module_body(mb)] => {
+                let (imports, exports, assignments) = mb;
+                Module{ name: mi, imports, exports, assignments }
+            },
         ))
     }
 
-    fn module_body(node: Node) -> Result<Vec<Assignment>> {
+    fn module_body(node: Node) -> Result<(Vec<Import>, Vec<String>, Vec<Assignment>)> {
         Ok(match_nodes!(node.into_children();
-            [assignment_list(a)] => a,
-            [export_list(e), assignment_list(a)] => a,
-            [import_list(i), assignment_list(a)] => a,
-            [export_list(e), import_list(i), assignment_list(a)] => a,
+            [assignment_list(a)] => (vec![], vec![], a),
+            [export_list(e), assignment_list(a)] => (vec![], e, a),
+            [import_list(i), assignment_list(a)] => (i, vec![], a),
+            [export_list(e), import_list(i), assignment_list(a)] => (i, e, a),
         ))
     }
 
-    fn import_list(node: Node) -> Result<String> {
-        Ok(format!("{:?}", node.as_rule()))
-    }
-
-    fn export_list(node: Node) -> Result<String> {
-        Ok(format!("{:?}", node.as_rule()))
+    fn import_list(node: Node) -> Result<Vec<Import>> {
+        // Each clause is "<symbol, symbol, ...> FROM <Module>"; the whole
+        // list is wrapped in `IMPORTS ... ;`, and this rule's matched text
+        // still carries that leading keyword, so strip it before matching.
+        let text = node.as_str().trim_start().trim_start_matches("IMPORTS");
+        let re = Regex::new(r"([A-Za-z0-9_,\s-]+?)\s+FROM\s+([A-Za-z][A-Za-z0-9-]*)").unwrap();
+        Ok(re
+            .captures_iter(text)
+            .map(|c| {
+                let symbols = c[1]
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                Import { symbols, from_module: c[2].to_string() }
+            })
+            .collect())
+    }
+
+    fn export_list(node: Node) -> Result<Vec<String>> {
+        let text = node.as_str();
+        let inner = text.trim_start_matches("EXPORTS").trim_end_matches(';');
+        Ok(inner
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect())
     }
 
     fn assignment_list(node: Node) -> Result<Vec<Assignment>> {
@@ -76,19 +264,85 @@ impl MibParser {
     }
 
     fn value_assignment(node: Node) -> Result<Assignment> {
+        let location = location_of(&node);
         Ok(match_nodes!(node.into_children();
-            [identifier(i), some_type(t), value(v)] => Assignment{name: i, a_type: t, value:Some(v)}
+            [identifier(i), some_type(t), value(v)] => Assignment{name: i, a_type: t, value:Some(v), location}
         ))
     }
 
     fn type_assignment(node: Node) -> Result<Assignment> {
+        let location = location_of(&node);
+        Ok(match_nodes!(node.into_children();
+            [identifier(i), some_type(t)] => Assignment{name: i, a_type: t, value:None, location}
+        ))
+    }
+
+    fn some_type(node: Node) -> Result<SmiType> {
+        // `some_type` is an alternation over the builtin SMI scalars, a
+        // SEQUENCE OF, and the OBJECT-TYPE / MODULE-IDENTITY macro bodies.
+        // Pull out whichever sub-rule actually matched so we build the
+        // right variant instead of just echoing the rule name.
+        let text = node.as_str().trim();
+        if text.starts_with("MODULE-IDENTITY") || text.starts_with("OBJECT-TYPE") {
+            return Ok(SmiType::ObjectType(Box::new(object_type_from(node.clone())?)));
+        }
+        if text.starts_with("SEQUENCE") && !text.starts_with("SEQUENCE OF") {
+            return Ok(parse_sequence_fields(text));
+        }
+
+        for child in node.clone().children() {
+            match child.as_rule() {
+                Rule::sequence_of_type => return MibParser::sequence_of_type(child),
+                Rule::snmp_update_part | Rule::compliance_group | Rule::snmp_module_part => {
+                    return Ok(SmiType::ObjectType(Box::new(object_type_from(node.clone())?)));
+                }
+                _ => {}
+            }
+        }
+
+        let constraint = node
+            .clone()
+            .children()
+            .find(|c| c.as_rule() == Rule::constraint_list)
+            .map(MibParser::constraint_list)
+            .transpose()?;
+
+        Ok(if text.starts_with("INTEGER") || text.starts_with("Integer32") {
+            SmiType::Integer32(constraint)
+        } else if text.starts_with("Unsigned32") {
+            SmiType::Unsigned32(constraint)
+        } else if text.starts_with("Counter32") {
+            SmiType::Counter32(constraint)
+        } else if text.starts_with("Gauge32") {
+            SmiType::Gauge32(constraint)
+        } else if text.starts_with("TimeTicks") {
+            SmiType::TimeTicks(constraint)
+        } else if text.starts_with("OCTET STRING") {
+            SmiType::OctetString(constraint)
+        } else if text.starts_with("OBJECT IDENTIFIER") {
+            SmiType::ObjectIdentifier
+        } else if text.starts_with("BITS") {
+            SmiType::Bits(constraint)
+        } else {
+            SmiType::Named(text.split_whitespace().next().unwrap_or(text).to_string())
+        })
+    }
+
+    fn sequence_of_type(node: Node) -> Result<SmiType> {
         Ok(match_nodes!(node.into_children();
-            [identifier(i), some_type(t)] => Assignment{name: i, a_type: t, value:None}
+            [some_type(t)] => SmiType::SequenceOf(Box::new(t)),
         ))
     }
 
-    fn some_type(node: Node) -> Result<String> {
-        Ok(format!("{:?}", node.as_rule()))
+    fn constraint_list(node: Node) -> Result<Constraint> {
+        let text = node.as_str();
+        let re = Regex::new(r"(-?\d+)\s*\.\.\s*(-?\d+)").unwrap();
+        let caps = re
+            .captures(text)
+            .ok_or_else(|| node.error("expected a min..max range in constraint".to_string()))?;
+        let min = caps[1].parse().map_err(|e| node.error(e))?;
+        let max = caps[2].parse().map_err(|e| node.error(e))?;
+        Ok(Constraint { min, max })
     }
 
     fn value(node: Node) -> Result<String> {
@@ -103,7 +357,7 @@ impl MibParser {
     }
 
     fn object_identifier_value(node: Node) -> Result<String> {
-        Ok(format!("{:?}", node.as_rule()))
+        Ok(node.as_str().to_owned())
     }
 
     fn identifier(node: Node) -> Result<String> {
@@ -147,6 +401,141 @@ impl MibParser {
     }
 }
 
+// Pull the SYNTAX/ACCESS/STATUS/DESCRIPTION/INDEX clauses of an OBJECT-TYPE
+// (or the analogous MODULE-IDENTITY parts) out of its macro body. The
+// grammar doesn't break these clauses into their own Pest rules yet, so we
+// scan the macro's matched text directly, the same way `inner_string`
+// already reaches for `Regex` rather than a dedicated sub-rule.
+fn object_type_from(node: Node) -> Result<ObjectType> {
+    let text = node.as_str();
+
+    let access = Regex::new(r"(?:MAX-ACCESS|ACCESS)\s+([A-Za-z-]+)")
+        .unwrap()
+        .captures(text)
+        .and_then(|c| access_from_str(&c[1]));
+
+    let status = Regex::new(r"STATUS\s+([A-Za-z-]+)")
+        .unwrap()
+        .captures(text)
+        .and_then(|c| status_from_str(&c[1]));
+
+    let description = Regex::new(r#"DESCRIPTION\s+"((?:[^"]|"")*)""#)
+        .unwrap()
+        .captures(text)
+        .map(|c| c[1].replace("\"\"", "\""));
+
+    let index = Regex::new(r"INDEX\s*\{([^}]*)\}")
+        .unwrap()
+        .captures(text)
+        .map(|c| {
+            c[1].split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let defval = Regex::new(r"DEFVAL\s*\{([^}]*)\}").unwrap().captures(text).map(|c| c[1].trim().to_string());
+
+    // Capture everything up to whichever clause comes next, not just the
+    // first token, so multi-word syntaxes (`OCTET STRING`, `SEQUENCE OF
+    // DiskEntry`, ...) aren't truncated to their first word.
+    let syntax = Regex::new(r"(?s)SYNTAX\s+(.*?)(?:\s+(?:MAX-ACCESS|ACCESS|STATUS|DESCRIPTION|INDEX|DEFVAL|UNITS|REFERENCE)\b|\s*$)")
+        .unwrap()
+        .captures(text)
+        .map(|c| scalar_or_named_from_text(c[1].trim()))
+        .unwrap_or_else(|| SmiType::Named("UNKNOWN".to_string()));
+
+    Ok(ObjectType { syntax: Box::new(syntax), access, status, description, index, defval })
+}
+
+fn constraint_from_text(text: &str) -> Option<Constraint> {
+    let re = Regex::new(r"(-?\d+)\s*\.\.\s*(-?\d+)").unwrap();
+    let caps = re.captures(text)?;
+    Some(Constraint { min: caps[1].parse().ok()?, max: caps[2].parse().ok()? })
+}
+
+/// Classify a bare type clause (a SYNTAX value, or a SEQUENCE field's type)
+/// the same way `some_type`'s fallback does, but working off already-
+/// extracted text rather than a Pest `Node`.
+fn scalar_or_named_from_text(text: &str) -> SmiType {
+    let text = text.trim();
+    if let Some(rest) = text.strip_prefix("SEQUENCE OF") {
+        return SmiType::SequenceOf(Box::new(scalar_or_named_from_text(rest.trim())));
+    }
+
+    let constraint = constraint_from_text(text);
+    if text.starts_with("INTEGER") || text.starts_with("Integer32") {
+        SmiType::Integer32(constraint)
+    } else if text.starts_with("Unsigned32") {
+        SmiType::Unsigned32(constraint)
+    } else if text.starts_with("Counter32") {
+        SmiType::Counter32(constraint)
+    } else if text.starts_with("Gauge32") {
+        SmiType::Gauge32(constraint)
+    } else if text.starts_with("TimeTicks") {
+        SmiType::TimeTicks(constraint)
+    } else if text.starts_with("OCTET STRING") {
+        SmiType::OctetString(constraint)
+    } else if text.starts_with("OBJECT IDENTIFIER") {
+        SmiType::ObjectIdentifier
+    } else if text.starts_with("BITS") {
+        SmiType::Bits(constraint)
+    } else {
+        SmiType::Named(text.split_whitespace().next().unwrap_or(text).to_string())
+    }
+}
+
+/// Parse an inline `SEQUENCE { field Type, field Type, ... }` record into
+/// its fields, e.g. `SEQUENCE { diskIndex Integer32, diskStatus INTEGER }`.
+fn parse_sequence_fields(text: &str) -> SmiType {
+    let inner = text
+        .trim_start()
+        .strip_prefix("SEQUENCE")
+        .unwrap_or(text)
+        .trim_start()
+        .trim_start_matches('{')
+        .trim_end()
+        .trim_end_matches('}');
+
+    let fields = inner
+        .split(',')
+        .filter_map(|field| {
+            let field = field.trim();
+            if field.is_empty() {
+                return None;
+            }
+            let mut parts = field.splitn(2, |c: char| c.is_whitespace());
+            let name = parts.next()?.to_string();
+            let field_type = scalar_or_named_from_text(parts.next().unwrap_or("").trim());
+            Some((name, field_type))
+        })
+        .collect();
+
+    SmiType::Sequence { fields }
+}
+
+fn access_from_str(s: &str) -> Option<Access> {
+    match s {
+        "read-only" => Some(Access::ReadOnly),
+        "read-write" => Some(Access::ReadWrite),
+        "read-create" => Some(Access::ReadCreate),
+        "accessible-for-notify" => Some(Access::AccessibleForNotify),
+        "not-accessible" => Some(Access::NotAccessible),
+        _ => None,
+    }
+}
+
+fn status_from_str(s: &str) -> Option<Status> {
+    match s {
+        "current" => Some(Status::Current),
+        "deprecated" => Some(Status::Deprecated),
+        "obsolete" => Some(Status::Obsolete),
+        "mandatory" => Some(Status::Mandatory),
+        _ => None,
+    }
+}
+
 //
 // Helpers to print a readable parse tree, mainly for debug purposes
 //
@@ -280,7 +669,86 @@ mod tests {
             "Second draft.""#;
 
         let node = parse(Rule::some_type, input);
-        print_node(node)
+        let a_type = MibParser::some_type(node).unwrap();
+        match a_type {
+            SmiType::ObjectType(ot) => {
+                assert_eq!(ot.description.as_deref(), Some("Characteristics of the disk information"));
+            }
+            other => panic!("expected SmiType::ObjectType, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn some_type_captures_multi_word_syntax_clauses() {
+        let octet_string = r#"OBJECT-TYPE
+            SYNTAX OCTET STRING
+            MAX-ACCESS read-only
+            STATUS current
+            DESCRIPTION
+                "A disk serial number.""#;
+        let node = parse(Rule::some_type, octet_string);
+        match MibParser::some_type(node).unwrap() {
+            SmiType::ObjectType(ot) => assert_eq!(*ot.syntax, SmiType::OctetString(None)),
+            other => panic!("expected SmiType::ObjectType, got {:?}", other),
+        }
+
+        let sequence_of = r#"OBJECT-TYPE
+            SYNTAX SEQUENCE OF DiskEntry
+            MAX-ACCESS not-accessible
+            STATUS current
+            DESCRIPTION
+                "A table of disks.""#;
+        let node = parse(Rule::some_type, sequence_of);
+        match MibParser::some_type(node).unwrap() {
+            SmiType::ObjectType(ot) => {
+                assert_eq!(*ot.syntax, SmiType::SequenceOf(Box::new(SmiType::Named("DiskEntry".to_string()))));
+            }
+            other => panic!("expected SmiType::ObjectType, got {:?}", other),
+        }
+
+        let object_identifier = r#"OBJECT-TYPE
+            SYNTAX OBJECT IDENTIFIER
+            MAX-ACCESS read-only
+            STATUS current
+            DESCRIPTION
+                "An object identifier.""#;
+        let node = parse(Rule::some_type, object_identifier);
+        match MibParser::some_type(node).unwrap() {
+            SmiType::ObjectType(ot) => assert_eq!(*ot.syntax, SmiType::ObjectIdentifier),
+            other => panic!("expected SmiType::ObjectType, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn object_type_from_captures_defval() {
+        let input = r#"OBJECT-TYPE
+            SYNTAX Integer32
+            MAX-ACCESS read-write
+            STATUS current
+            DESCRIPTION
+                "Number of retries before giving up."
+            DEFVAL { 9 }"#;
+        let node = parse(Rule::some_type, input);
+        match MibParser::some_type(node).unwrap() {
+            SmiType::ObjectType(ot) => assert_eq!(ot.defval.as_deref(), Some("9")),
+            other => panic!("expected SmiType::ObjectType, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn some_type_parses_inline_sequence_fields() {
+        let input = "SEQUENCE { diskIndex Integer32, diskStatus INTEGER }";
+        let node = parse(Rule::some_type, input);
+        let a_type = MibParser::some_type(node).unwrap();
+        assert_eq!(
+            a_type,
+            SmiType::Sequence {
+                fields: vec![
+                    ("diskIndex".to_string(), SmiType::Integer32(None)),
+                    ("diskStatus".to_string(), SmiType::Integer32(None)),
+                ],
+            },
+        );
     }
 
     #[test]
@@ -291,11 +759,23 @@ mod tests {
         print_node(node)
     }
 
+    #[test]
+    fn object_identifier_value() {
+        let input = "{ iso org(3) dod(6) 1 }";
+
+        let node = parse(Rule::object_identifier_value, input);
+        let value = MibParser::object_identifier_value(node).unwrap();
+        assert_eq!(value, input);
+    }
+
     #[test]
     fn constraint_list() {
         let input = "( SIZE (0..63) )";
         let node = parse(Rule::constraint_list, input);
-        print_node(node)        
+        print_node(node.clone());
+
+        let constraint = MibParser::constraint_list(node).unwrap();
+        assert_eq!(constraint, Constraint { min: 0, max: 63 });
     }
 
     #[test]
@@ -326,7 +806,25 @@ mod tests {
                     FROM SNMPv2-SMI;"#;
 
         let node = parse(Rule::import_list, input);
-        print_node(node)
+        let imports = MibParser::import_list(node).unwrap();
+        assert_eq!(
+            imports,
+            vec![
+                Import {
+                    symbols: vec!["OBJECT-GROUP".to_string(), "MODULE-COMPLIANCE".to_string()],
+                    from_module: "SNMPv2-CONF".to_string(),
+                },
+                Import {
+                    symbols: vec![
+                        "enterprises".to_string(),
+                        "MODULE-IDENTITY".to_string(),
+                        "OBJECT-TYPE".to_string(),
+                        "Integer32".to_string(),
+                    ],
+                    from_module: "SNMPv2-SMI".to_string(),
+                },
+            ]
+        );
     }
 
     #[test]
@@ -400,6 +898,74 @@ mod tests {
         print_node(node)
     }
 
+    #[test]
+    fn resilient_parse_skips_malformed_assignment_and_keeps_the_rest() {
+        let input = r#"SYNOLOGY-SMI DEFINITIONS ::= BEGIN
+
+synology OBJECT IDENTIFIER ::= { enterprises 6574 }
+
+this is not a valid assignment
+
+synoDisk OBJECT IDENTIFIER ::= { synology 2 }
+
+END"#;
+
+        let fail_fast = parse_mib(input, &ParseOptions::default());
+        assert!(fail_fast.is_err());
+
+        let options = ParseOptions { recover: true, ..ParseOptions::default() };
+        let result = parse_mib_resilient(input, &options);
+
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].rule, "assignment");
+
+        let names: Vec<&str> = result.mib.modules[0].assignments.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["synology", "synoDisk"]);
+    }
+
+    #[test]
+    fn resilient_parse_keeps_a_blank_paragraph_inside_one_description() {
+        let input = r#"SYNOLOGY-SMI DEFINITIONS ::= BEGIN
+
+synoDisk MODULE-IDENTITY
+    LAST-UPDATED "201309110000Z"
+    ORGANIZATION "www.synology.com"
+    CONTACT-INFO
+        "postal: Jay Pan"
+    DESCRIPTION
+        "First paragraph.
+
+        Second paragraph, after a blank line."
+    ::= { synology 2 }
+
+synoDiskCount OBJECT IDENTIFIER ::= { synoDisk 1 }
+
+END"#;
+
+        let options = ParseOptions { recover: true, ..ParseOptions::default() };
+        let result = parse_mib_resilient(input, &options);
+
+        assert!(result.errors.is_empty(), "expected no diagnostics, got {:?}", result.errors);
+        let names: Vec<&str> = result.mib.modules[0].assignments.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["synoDisk", "synoDiskCount"]);
+    }
+
+    #[test]
+    fn resilient_parse_splits_back_to_back_assignments_with_no_blank_line() {
+        let input = r#"SYNOLOGY-SMI DEFINITIONS ::= BEGIN
+synology OBJECT IDENTIFIER ::= { enterprises 6574 }
+synoDisk OBJECT IDENTIFIER ::= { synology 2 }
+synoDiskCount OBJECT IDENTIFIER ::= { synoDisk 1 }
+END"#;
+
+        let options = ParseOptions { recover: true, ..ParseOptions::default() };
+        let result = parse_mib_resilient(input, &options);
+
+        assert!(result.errors.is_empty(), "expected no diagnostics, got {:?}", result.errors);
+        let names: Vec<&str> = result.mib.modules[0].assignments.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["synology", "synoDisk", "synoDiskCount"]);
+    }
+
     //
     // test helpers
     //
@@ -0,0 +1,238 @@
+//! Loads several MIB files together and resolves IMPORTS across them.
+//!
+//! A single `parse_mib` call only ever sees one file's text, so a name
+//! brought in via `FROM SNMPv2-SMI` can't be checked against anything.
+//! `MibRepository` collects the modules from every file it's given and
+//! resolves each one's IMPORTS against the others: which module to load
+//! first, which imports are missing their source module entirely, and
+//! which name a module's own `EXPORTS` (or simply never defines) withholds.
+
+use std::collections::{HashMap, VecDeque};
+
+use pest_consume::Error;
+
+use crate::{parse_mib, Module, ParseOptions, Rule};
+
+/// A problem found while resolving IMPORTS across a set of loaded modules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepositoryError {
+    /// `wanted_by` imports `symbol` from a module that was never loaded.
+    MissingModule { wanted_by: String, symbol: String, module: String },
+    /// `wanted_by` imports `symbol` from `module`, but `module` doesn't
+    /// export (or define) it.
+    UnsatisfiedImport { wanted_by: String, symbol: String, module: String },
+    /// The modules' IMPORTS form a cycle, so no load order exists.
+    CircularImport { cycle: Vec<String> },
+}
+
+/// A collection of MIB modules loaded from one or more source files, with
+/// their cross-module IMPORTS resolved.
+#[derive(Debug, Default)]
+pub struct MibRepository {
+    modules: HashMap<String, Module>,
+}
+
+impl MibRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse `text` and add every module definition it contains.
+    pub fn load_str(&mut self, text: &str) -> Result<(), Error<Rule>> {
+        let mib = parse_mib(text, &ParseOptions::default())?;
+        for module in mib.modules {
+            self.modules.insert(module.name.clone(), module);
+        }
+        Ok(())
+    }
+
+    /// The modules loaded so far, keyed by name.
+    pub fn modules(&self) -> &HashMap<String, Module> {
+        &self.modules
+    }
+
+    /// Resolve every loaded module's IMPORTS against the rest of the
+    /// repository. Returns a load order with dependencies before their
+    /// dependents, plus diagnostics for any import whose module was never
+    /// loaded, any import of a symbol its source module never exports, and
+    /// any circular import chain (which leaves some modules out of the
+    /// returned order entirely).
+    pub fn resolve(&self) -> (Vec<String>, Vec<RepositoryError>) {
+        let mut errors = Vec::new();
+
+        for module in self.modules.values() {
+            for import in &module.imports {
+                match self.modules.get(&import.from_module) {
+                    None => {
+                        for symbol in &import.symbols {
+                            errors.push(RepositoryError::MissingModule {
+                                wanted_by: module.name.clone(),
+                                symbol: symbol.clone(),
+                                module: import.from_module.clone(),
+                            });
+                        }
+                    }
+                    Some(source) => {
+                        for symbol in &import.symbols {
+                            if !source_satisfies(source, symbol) {
+                                errors.push(RepositoryError::UnsatisfiedImport {
+                                    wanted_by: module.name.clone(),
+                                    symbol: symbol.clone(),
+                                    module: import.from_module.clone(),
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let (order, cycle) = self.topological_order();
+        if let Some(cycle) = cycle {
+            errors.push(RepositoryError::CircularImport { cycle });
+        }
+
+        (order, errors)
+    }
+
+    /// Kahn's algorithm over the "imports from" edges between loaded
+    /// modules. Missing modules (an import with no matching loaded module)
+    /// don't contribute an edge, since they're already reported separately.
+    fn topological_order(&self) -> (Vec<String>, Option<Vec<String>>) {
+        let mut in_degree: HashMap<&str, usize> =
+            self.modules.keys().map(|name| (name.as_str(), 0)).collect();
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+
+        for module in self.modules.values() {
+            for import in &module.imports {
+                if self.modules.contains_key(&import.from_module) {
+                    *in_degree.get_mut(module.name.as_str()).unwrap() += 1;
+                    dependents.entry(import.from_module.as_str()).or_default().push(module.name.as_str());
+                }
+            }
+        }
+
+        let mut ready: VecDeque<&str> = {
+            let mut names: Vec<&str> =
+                in_degree.iter().filter(|(_, &d)| d == 0).map(|(&name, _)| name).collect();
+            names.sort();
+            names.into()
+        };
+
+        let mut order = Vec::new();
+        while let Some(name) = ready.pop_front() {
+            order.push(name.to_string());
+            if let Some(deps) = dependents.get(name) {
+                for &dep in deps {
+                    let degree = in_degree.get_mut(dep).unwrap();
+                    *degree -= 1;
+                    if *degree == 0 {
+                        ready.push_back(dep);
+                    }
+                }
+            }
+        }
+
+        if order.len() == self.modules.len() {
+            (order, None)
+        } else {
+            let mut cycle: Vec<String> =
+                self.modules.keys().filter(|name| !order.contains(name)).cloned().collect();
+            cycle.sort();
+            (order, Some(cycle))
+        }
+    }
+}
+
+/// Whether `module` makes `symbol` available to importers: with an
+/// `EXPORTS` clause, only the names listed there count; with no `EXPORTS`
+/// clause at all, everything the module defines is implicitly exported.
+fn source_satisfies(module: &Module, symbol: &str) -> bool {
+    if module.exports.is_empty() {
+        return module.assignments.iter().any(|a| a.name == symbol);
+    }
+    module.exports.iter().any(|e| e == symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Assignment, Import, Location, SmiType};
+
+    fn module(name: &str, imports: Vec<Import>, assignment_names: &[&str]) -> Module {
+        Module {
+            name: name.to_string(),
+            imports,
+            exports: vec![],
+            assignments: assignment_names
+                .iter()
+                .map(|n| Assignment {
+                    name: n.to_string(),
+                    a_type: SmiType::ObjectIdentifier,
+                    value: None,
+                    location: Location::default(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn resolves_in_dependency_order() {
+        let mut repo = MibRepository::new();
+        repo.modules.insert(
+            "CHILD-MIB".to_string(),
+            module(
+                "CHILD-MIB",
+                vec![Import { symbols: vec!["enterprises".to_string()], from_module: "PARENT-MIB".to_string() }],
+                &["childThing"],
+            ),
+        );
+        repo.modules.insert("PARENT-MIB".to_string(), module("PARENT-MIB", vec![], &["enterprises"]));
+
+        let (order, errors) = repo.resolve();
+        assert!(errors.is_empty());
+        assert_eq!(order, vec!["PARENT-MIB".to_string(), "CHILD-MIB".to_string()]);
+    }
+
+    #[test]
+    fn reports_missing_module() {
+        let mut repo = MibRepository::new();
+        repo.modules.insert(
+            "CHILD-MIB".to_string(),
+            module(
+                "CHILD-MIB",
+                vec![Import { symbols: vec!["enterprises".to_string()], from_module: "PARENT-MIB".to_string() }],
+                &["childThing"],
+            ),
+        );
+
+        let (_, errors) = repo.resolve();
+        assert_eq!(
+            errors,
+            vec![RepositoryError::MissingModule {
+                wanted_by: "CHILD-MIB".to_string(),
+                symbol: "enterprises".to_string(),
+                module: "PARENT-MIB".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_circular_imports() {
+        let mut repo = MibRepository::new();
+        repo.modules.insert(
+            "A-MIB".to_string(),
+            module("A-MIB", vec![Import { symbols: vec!["b".to_string()], from_module: "B-MIB".to_string() }], &["a"]),
+        );
+        repo.modules.insert(
+            "B-MIB".to_string(),
+            module("B-MIB", vec![Import { symbols: vec!["a".to_string()], from_module: "A-MIB".to_string() }], &["b"]),
+        );
+
+        let (_, errors) = repo.resolve();
+        assert_eq!(
+            errors,
+            vec![RepositoryError::CircularImport { cycle: vec!["A-MIB".to_string(), "B-MIB".to_string()] }]
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! Semantic validation over a parsed `Module`.
+//!
+//! The grammar already recognizes constraints, SEQUENCE fields, and INDEX
+//! clauses, but nothing checks that they're internally consistent. This
+//! pass walks the structured model (see `model.rs`) and reports every
+//! problem it finds in one go, rather than stopping at the first one.
+
+use std::collections::HashSet;
+
+use crate::{Assignment, Constraint, Location, Module, SmiType};
+
+/// What's wrong with a given `ValidationError`'s location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationErrorKind {
+    /// A DEFVAL or default value falls outside its declared range/SIZE
+    /// constraint.
+    ValueOutOfRange { value: i64, min: i64, max: i64 },
+    /// A SEQUENCE field's type is neither defined nor imported in this
+    /// module.
+    UndefinedType { name: String },
+    /// An OBJECT-TYPE's INDEX names a column that isn't a field of any
+    /// SEQUENCE in this module.
+    UndefinedIndexColumn { name: String },
+    /// Two assignments in the same module share a name.
+    DuplicateName,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub location: Location,
+    pub kind: ValidationErrorKind,
+}
+
+/// A handful of SMI/ASN.1 names that are always in scope, regardless of
+/// what this module imports.
+const BUILTIN_TYPES: &[&str] = &[
+    "INTEGER",
+    "OCTET STRING",
+    "OBJECT IDENTIFIER",
+    "IpAddress",
+    "Counter64",
+    "DisplayString",
+    "PhysAddress",
+    "RowStatus",
+    "TruthValue",
+    "TestAndIncr",
+    "TimeStamp",
+];
+
+/// Validate every assignment in `module`, returning every problem found
+/// rather than stopping at the first one.
+pub fn validate_module(module: &Module) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    errors.extend(duplicate_names(module));
+
+    let imported: HashSet<&str> =
+        module.imports.iter().flat_map(|i| i.symbols.iter().map(String::as_str)).collect();
+    let defined: HashSet<&str> = module.assignments.iter().map(|a| a.name.as_str()).collect();
+    let sequence_field_names: HashSet<&str> = module
+        .assignments
+        .iter()
+        .filter_map(|a| match &a.a_type {
+            SmiType::Sequence { fields } => Some(fields.iter().map(|(name, _)| name.as_str())),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    for assignment in &module.assignments {
+        match &assignment.a_type {
+            SmiType::Sequence { fields } => {
+                for (_, field_type) in fields {
+                    if let SmiType::Named(name) = field_type {
+                        if !BUILTIN_TYPES.contains(&name.as_str())
+                            && !defined.contains(name.as_str())
+                            && !imported.contains(name.as_str())
+                        {
+                            errors.push(ValidationError {
+                                location: assignment.location.clone(),
+                                kind: ValidationErrorKind::UndefinedType { name: name.clone() },
+                            });
+                        }
+                    }
+                }
+            }
+            SmiType::ObjectType(object_type) => {
+                for index_column in &object_type.index {
+                    if !sequence_field_names.contains(index_column.as_str()) {
+                        errors.push(ValidationError {
+                            location: assignment.location.clone(),
+                            kind: ValidationErrorKind::UndefinedIndexColumn { name: index_column.clone() },
+                        });
+                    }
+                }
+                if let Some(error) =
+                    out_of_range(&assignment.location, object_type.defval.as_deref(), constraint_of(&object_type.syntax))
+                {
+                    errors.push(error);
+                }
+            }
+            scalar => {
+                if let Some(error) = out_of_range(&assignment.location, assignment.value.as_deref(), constraint_of(scalar)) {
+                    errors.push(error);
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+fn duplicate_names(module: &Module) -> Vec<ValidationError> {
+    let mut seen = HashSet::new();
+    module
+        .assignments
+        .iter()
+        .filter(|a| !seen.insert(a.name.as_str()))
+        .map(|a| ValidationError { location: a.location.clone(), kind: ValidationErrorKind::DuplicateName })
+        .collect()
+}
+
+fn constraint_of(a_type: &SmiType) -> Option<&Constraint> {
+    match a_type {
+        SmiType::Integer32(c)
+        | SmiType::Unsigned32(c)
+        | SmiType::Counter32(c)
+        | SmiType::Gauge32(c)
+        | SmiType::TimeTicks(c)
+        | SmiType::OctetString(c)
+        | SmiType::Bits(c) => c.as_ref(),
+        _ => None,
+    }
+}
+
+fn out_of_range(location: &Location, value: Option<&str>, constraint: Option<&Constraint>) -> Option<ValidationError> {
+    let constraint = constraint?;
+    let value: i64 = value?.trim().parse().ok()?;
+    if value < constraint.min || value > constraint.max {
+        Some(ValidationError {
+            location: location.clone(),
+            kind: ValidationErrorKind::ValueOutOfRange { value, min: constraint.min, max: constraint.max },
+        })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Import, ObjectType};
+
+    fn assignment(name: &str, a_type: SmiType, value: Option<&str>) -> Assignment {
+        Assignment { name: name.to_string(), a_type, value: value.map(str::to_string), location: Location::default() }
+    }
+
+    fn module(assignments: Vec<Assignment>) -> Module {
+        Module { name: "TEST-MIB".to_string(), imports: vec![], exports: vec![], assignments }
+    }
+
+    #[test]
+    fn flags_value_outside_constraint() {
+        let m = module(vec![assignment(
+            "retryCount",
+            SmiType::Integer32(Some(Constraint { min: 0, max: 5 })),
+            Some("9"),
+        )]);
+
+        let errors = validate_module(&m);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ValidationErrorKind::ValueOutOfRange { value: 9, min: 0, max: 5 });
+    }
+
+    #[test]
+    fn flags_defval_outside_object_types_constraint() {
+        let m = module(vec![assignment(
+            "retryCount",
+            SmiType::ObjectType(Box::new(ObjectType {
+                syntax: Box::new(SmiType::Integer32(Some(Constraint { min: 0, max: 5 }))),
+                access: None,
+                status: None,
+                description: None,
+                index: vec![],
+                defval: Some("9".to_string()),
+            })),
+            None,
+        )]);
+
+        let errors = validate_module(&m);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ValidationErrorKind::ValueOutOfRange { value: 9, min: 0, max: 5 });
+    }
+
+    #[test]
+    fn flags_undefined_sequence_field_type() {
+        let m = module(vec![assignment(
+            "DiskEntry",
+            SmiType::Sequence { fields: vec![("diskStatus".to_string(), SmiType::Named("DiskStatus".to_string()))] },
+            None,
+        )]);
+
+        let errors = validate_module(&m);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ValidationErrorKind::UndefinedType { name: "DiskStatus".to_string() });
+    }
+
+    #[test]
+    fn does_not_flag_an_imported_sequence_field_type() {
+        let mut m = module(vec![assignment(
+            "DiskEntry",
+            SmiType::Sequence { fields: vec![("diskIndex".to_string(), SmiType::Named("Integer32".to_string()))] },
+            None,
+        )]);
+        m.imports.push(Import { symbols: vec!["Integer32".to_string()], from_module: "SNMPv2-SMI".to_string() });
+
+        assert!(validate_module(&m).is_empty());
+    }
+
+    #[test]
+    fn flags_index_column_missing_from_sequence() {
+        let m = module(vec![
+            assignment(
+                "DiskEntry",
+                SmiType::Sequence { fields: vec![("diskIndex".to_string(), SmiType::Integer32(None))] },
+                None,
+            ),
+            assignment(
+                "diskTable",
+                SmiType::ObjectType(Box::new(ObjectType {
+                    syntax: Box::new(SmiType::Named("DiskEntry".to_string())),
+                    access: None,
+                    status: None,
+                    description: None,
+                    index: vec!["diskSerial".to_string()],
+                    defval: None,
+                })),
+                None,
+            ),
+        ]);
+
+        let errors = validate_module(&m);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ValidationErrorKind::UndefinedIndexColumn { name: "diskSerial".to_string() });
+    }
+
+    #[test]
+    fn flags_duplicate_assignment_names() {
+        let m = module(vec![
+            assignment("synoDisk", SmiType::ObjectIdentifier, None),
+            assignment("synoDisk", SmiType::ObjectIdentifier, None),
+        ]);
+
+        let errors = validate_module(&m);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ValidationErrorKind::DuplicateName);
+    }
+}